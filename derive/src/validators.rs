@@ -0,0 +1,371 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Expr, Lit, Meta, NestedMeta, Result};
+
+/// A single parsed entry of a `#[graphql(validator(...))]` attribute, after
+/// the boolean combinators (`and`/`or`/`not`) have been resolved into a
+/// tree.
+pub enum Validator {
+    Maximum(f64),
+    Minimum(f64),
+    /// `custom = "SomeValidator::new(...)"` — an expression constructing a
+    /// value that implements `CustomValidator<T>`.
+    Custom(Expr),
+    /// `and(...)` — every child must pass; stops at the first failure.
+    And(Vec<Validator>),
+    /// `or(...)` — at least one child must pass; on failure, the messages
+    /// of every child are joined with `"; "`.
+    Or(Vec<Validator>),
+    /// `not(...)` — the child must fail.
+    Not(Box<Validator>),
+    /// `min_items = N` — the list itself (not its elements) must have at
+    /// least `N` entries. Distinct from the element-wise `list` flag.
+    MinItems(usize),
+    /// `max_items = N` — the list itself must have at most `N` entries.
+    MaxItems(usize),
+    /// `unique_items` — every element of the list must be distinct.
+    ///
+    /// The generated check puts each element in a `HashSet`, so the list's
+    /// element type must implement `Eq + Hash`; this isn't checked while
+    /// parsing the attribute (we don't have the field's type here), so
+    /// putting `unique_items` on e.g. a `Vec<f64>` field surfaces as an
+    /// ordinary "the trait bound `f64: Eq` is not satisfied" error pointing
+    /// into the derive-generated code rather than at the attribute.
+    UniqueItems,
+}
+
+/// Parse the contents of a `validator(...)` attribute into its individual
+/// entries. Multiple top-level entries are implicitly `and`-ed together,
+/// same as before combinators existed.
+pub fn parse_validators(nested: &[NestedMeta]) -> Result<Vec<Validator>> {
+    nested.iter().map(parse_validator).collect()
+}
+
+fn parse_validator(meta: &NestedMeta) -> Result<Validator> {
+    match meta {
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("maximum") => {
+            Ok(Validator::Maximum(lit_to_f64(&nv.lit)?))
+        }
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("minimum") => {
+            Ok(Validator::Minimum(lit_to_f64(&nv.lit)?))
+        }
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("min_items") => {
+            Ok(Validator::MinItems(lit_to_usize(&nv.lit)?))
+        }
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("max_items") => {
+            Ok(Validator::MaxItems(lit_to_usize(&nv.lit)?))
+        }
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("unique_items") => {
+            Ok(Validator::UniqueItems)
+        }
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("custom") => {
+            let expr: Expr = match &nv.lit {
+                Lit::Str(s) => s.parse()?,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &nv.lit,
+                        "custom validator must be a string literal expression",
+                    ))
+                }
+            };
+            Ok(Validator::Custom(expr))
+        }
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("and") => Ok(Validator::And(
+            list.nested.iter().map(parse_validator).collect::<Result<_>>()?,
+        )),
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("or") => Ok(Validator::Or(
+            list.nested.iter().map(parse_validator).collect::<Result<_>>()?,
+        )),
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("not") => {
+            if list.nested.len() != 1 {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    "not(...) takes exactly one validator",
+                ));
+            }
+            Ok(Validator::Not(Box::new(parse_validator(
+                list.nested.first().unwrap(),
+            )?)))
+        }
+        _ => Err(syn::Error::new_spanned(meta, "unknown validator")),
+    }
+}
+
+fn lit_to_usize(lit: &Lit) -> Result<usize> {
+    match lit {
+        Lit::Int(i) => i.base10_parse(),
+        Lit::Str(s) => s
+            .value()
+            .parse()
+            .map_err(|_| syn::Error::new_spanned(s, "expected a non-negative integer")),
+        _ => Err(syn::Error::new_spanned(lit, "expected a non-negative integer")),
+    }
+}
+
+fn lit_to_f64(lit: &Lit) -> Result<f64> {
+    match lit {
+        Lit::Str(s) => s
+            .value()
+            .parse()
+            .map_err(|_| syn::Error::new_spanned(s, "expected a number")),
+        Lit::Int(i) => i.base10_parse(),
+        Lit::Float(f) => f.base10_parse(),
+        _ => Err(syn::Error::new_spanned(lit, "expected a number")),
+    }
+}
+
+/// Generate an expression evaluating to `Result<(), ValidatorError>` that
+/// checks `value_ident` against a single validator node (recursing through
+/// combinators). `ctx_ident` is the in-scope `&Context<'_>`, needed by
+/// `custom` validators.
+///
+/// The error is always a `ValidatorError` rather than a plain `String` so
+/// that extensions attached by a `custom` validator (on its own or inside
+/// `and(...)`) survive all the way to `generate_validator`'s call to
+/// `InputValueError::custom_with_extensions`. A failing `or(...)` reports
+/// only its children's joined messages, and a failing `not(...)` only its
+/// own message; neither carries extensions.
+fn generate_check(
+    crate_name: &TokenStream,
+    validator: &Validator,
+    value_ident: &Ident,
+    ctx_ident: &Ident,
+) -> TokenStream {
+    match validator {
+        Validator::Maximum(n) => quote! {
+            if *#value_ident as f64 <= #n as f64 {
+                ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+            } else {
+                Err(#crate_name::validators::resolve_formatter(#ctx_ident)
+                    .maximum(*#value_ident as f64, #n as f64)
+                    .into())
+            }
+        },
+        Validator::Minimum(n) => quote! {
+            if *#value_ident as f64 >= #n as f64 {
+                ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+            } else {
+                Err(#crate_name::validators::resolve_formatter(#ctx_ident)
+                    .minimum(*#value_ident as f64, #n as f64)
+                    .into())
+            }
+        },
+        Validator::MinItems(n) => quote! {
+            if #value_ident.len() >= #n {
+                ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+            } else {
+                Err(#crate_name::validators::resolve_formatter(#ctx_ident)
+                    .min_items(#value_ident.len(), #n)
+                    .into())
+            }
+        },
+        Validator::MaxItems(n) => quote! {
+            if #value_ident.len() <= #n {
+                ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+            } else {
+                Err(#crate_name::validators::resolve_formatter(#ctx_ident)
+                    .max_items(#value_ident.len(), #n)
+                    .into())
+            }
+        },
+        // Requires the element type to impl `Eq + Hash`; see the doc
+        // comment on `Validator::UniqueItems`.
+        Validator::UniqueItems => quote! {
+            {
+                let mut seen = ::std::collections::HashSet::new();
+                if #value_ident.iter().all(|item| seen.insert(item)) {
+                    ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+                } else {
+                    Err(#crate_name::validators::resolve_formatter(#ctx_ident).unique_items().into())
+                }
+            }
+        },
+        Validator::Custom(expr) => quote! {
+            #crate_name::CustomValidator::check(&(#expr), #ctx_ident, #value_ident)
+                .await
+                .map_err(::std::convert::Into::<#crate_name::ValidatorError>::into)
+        },
+        Validator::And(children) => {
+            let checks = children
+                .iter()
+                .map(|child| generate_check(crate_name, child, value_ident, ctx_ident));
+            quote! {
+                async {
+                    #(#checks?;)*
+                    ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+                }.await
+            }
+        }
+        Validator::Or(children) => {
+            let checks = children
+                .iter()
+                .map(|child| generate_check(crate_name, child, value_ident, ctx_ident));
+            quote! {
+                {
+                    let mut errors: Vec<#crate_name::ValidatorError> = Vec::new();
+                    let mut ok = false;
+                    #(
+                        if !ok {
+                            match #checks {
+                                Ok(()) => ok = true,
+                                Err(err) => errors.push(err),
+                            }
+                        }
+                    )*
+                    if ok {
+                        ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(())
+                    } else {
+                        let message = errors
+                            .iter()
+                            .map(|err| err.message())
+                            .collect::<Vec<_>>()
+                            .join("; ");
+                        Err(#crate_name::ValidatorError::new(message))
+                    }
+                }
+            }
+        }
+        Validator::Not(child) => {
+            let check = generate_check(crate_name, child, value_ident, ctx_ident);
+            let negated_message = generate_negated_message(crate_name, child, value_ident, ctx_ident);
+            quote! {
+                match #check {
+                    Ok(()) => Err(#crate_name::ValidatorError::new(#negated_message)),
+                    Err(_) => ::std::result::Result::<(), #crate_name::ValidatorError>::Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// The message produced when a `not(...)`-wrapped validator's child
+/// unexpectedly passes. For `not(maximum = ...)` and `not(minimum = ...)`
+/// it is routed through `ValidatorMessageFormatter`, same as the validators
+/// they negate, so a registered formatter covers those too; any other
+/// negated child yields a fixed English message.
+fn generate_negated_message(
+    crate_name: &TokenStream,
+    validator: &Validator,
+    value_ident: &Ident,
+    ctx_ident: &Ident,
+) -> TokenStream {
+    match validator {
+        Validator::Maximum(n) => quote! {
+            #crate_name::validators::resolve_formatter(#ctx_ident)
+                .not_maximum(*#value_ident as f64, #n as f64)
+        },
+        Validator::Minimum(n) => quote! {
+            #crate_name::validators::resolve_formatter(#ctx_ident)
+                .not_minimum(*#value_ident as f64, #n as f64)
+        },
+        _ => quote! { "validator passed, expected it to fail".to_string() },
+    }
+}
+
+/// Generate the code that checks `value_ident` against a field/argument's
+/// top-level validators (implicitly `and`-ed together), where `value_ident`
+/// is the value itself (not a list).
+pub fn generate_validator(
+    crate_name: &TokenStream,
+    validators: &[Validator],
+    value_ident: &Ident,
+    ctx_ident: &Ident,
+) -> TokenStream {
+    generate_checks(crate_name, validators.iter(), value_ident, ctx_ident)
+}
+
+fn generate_checks<'a>(
+    crate_name: &TokenStream,
+    validators: impl Iterator<Item = &'a Validator>,
+    value_ident: &Ident,
+    ctx_ident: &Ident,
+) -> TokenStream {
+    let checks =
+        validators.map(|validator| generate_check(crate_name, validator, value_ident, ctx_ident));
+    quote! {
+        #(
+            if let Err(err) = #checks {
+                return Err(#crate_name::InputValueError::custom_with_extensions(err));
+            }
+        )*
+    }
+}
+
+/// Generate the code that checks `value_ident` (a `Vec<T>`) against a
+/// field/argument's validators, honoring the historical element-wise
+/// `list` flag alongside the list-scoped `min_items`/`max_items`/
+/// `unique_items` validators.
+///
+/// `MinItems`/`MaxItems`/`UniqueItems` always run once against the whole
+/// list. Every other validator (`maximum`, `minimum`, `custom`, and the
+/// combinators) runs once per element when `list` is set — the same
+/// behavior `generate_validator` already provides for non-list fields,
+/// just looped — and otherwise runs once against the whole `Vec` (so a
+/// `custom` validator there receives the `Vec<T>` itself). Nothing here
+/// rejects `maximum`/`minimum` on a `Vec` without `list`; the generated
+/// code then simply fails to compile.
+pub fn generate_list_validator(
+    crate_name: &TokenStream,
+    validators: &[Validator],
+    value_ident: &Ident,
+    ctx_ident: &Ident,
+    list: bool,
+) -> TokenStream {
+    let (list_scoped, element_wise): (Vec<_>, Vec<_>) = validators.iter().partition(|v| {
+        matches!(
+            v,
+            Validator::MinItems(_) | Validator::MaxItems(_) | Validator::UniqueItems
+        )
+    });
+
+    let list_checks = generate_checks(crate_name, list_scoped.into_iter(), value_ident, ctx_ident);
+
+    let element_checks = if element_wise.is_empty() {
+        quote! {}
+    } else if list {
+        let item_ident = Ident::new("__graphql_list_item", proc_macro2::Span::call_site());
+        let checks = generate_checks(
+            crate_name,
+            element_wise.into_iter(),
+            &item_ident,
+            ctx_ident,
+        );
+        quote! {
+            for #item_ident in #value_ident.iter() {
+                #checks
+            }
+        }
+    } else {
+        generate_checks(crate_name, element_wise.into_iter(), value_ident, ctx_ident)
+    };
+
+    quote! {
+        #list_checks
+        #element_checks
+    }
+}
+
+/// Generate the struct-level validator call for `#[derive(InputObject)]`
+/// when the struct itself carries `#[graphql(validator(custom = "..."))]`.
+///
+/// Runs after every field has been parsed, with the fully constructed value;
+/// the error is wrapped the same way field-level errors already are, so it
+/// reads as `... (occurred while parsing "MyInput")`.
+pub fn generate_struct_validator(
+    crate_name: &TokenStream,
+    struct_name: &str,
+    value_ident: &Ident,
+    ctx_ident: &Ident,
+    validator: Option<&Expr>,
+) -> TokenStream {
+    let validator = match validator {
+        Some(validator) => validator,
+        None => return quote! {},
+    };
+    quote! {
+        if let Err(err) = #crate_name::CustomValidator::check(&(#validator), #ctx_ident, &#value_ident).await {
+            let err: #crate_name::ValidatorError = err.into();
+            return Err(#crate_name::InputValueError::custom_with_extensions(err).propagate(#struct_name));
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Runtime types backing the `#[graphql(validator(...))]` attribute.
+//!
+//! The derive/proc-macro layer (see `async-graphql-derive/src/validators.rs`)
+//! parses the attribute and generates the code that invokes these types
+//! while parsing input values.
+
+use crate::{Context, ErrorExtensionValues};
+
+/// The error produced by a [`CustomValidator`].
+///
+/// Unlike a plain `String`, a `ValidatorError` can carry structured
+/// [`ErrorExtensionValues`] (an error code, extra fields, ...) that survive
+/// all the way into the [`crate::ServerError`] returned to the client.
+pub struct ValidatorError {
+    message: String,
+    extensions: Option<ErrorExtensionValues>,
+}
+
+impl ValidatorError {
+    /// Create a new `ValidatorError` with no extensions.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            extensions: None,
+        }
+    }
+
+    /// Attach extensions to this error, in the same style as
+    /// `ErrorExtensions::extend_with`.
+    pub fn extend_with<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&ValidatorError, &mut ErrorExtensionValues),
+    {
+        let mut extensions = self.extensions.take().unwrap_or_default();
+        f(&self, &mut extensions);
+        self.extensions = Some(extensions);
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn extensions(&self) -> Option<&ErrorExtensionValues> {
+        self.extensions.as_ref()
+    }
+
+    /// Split this error into its message and extensions, for the generated
+    /// code to fold into the surrounding `InputValueError`/`ServerError`.
+    pub fn into_parts(self) -> (String, Option<ErrorExtensionValues>) {
+        (self.message, self.extensions)
+    }
+}
+
+/// Existing validators that only ever produced a plain message keep
+/// compiling unchanged: `String` converts into a `ValidatorError` with no
+/// extensions attached.
+impl From<String> for ValidatorError {
+    fn from(message: String) -> Self {
+        ValidatorError::new(message)
+    }
+}
+
+impl From<&str> for ValidatorError {
+    fn from(message: &str) -> Self {
+        ValidatorError::new(message)
+    }
+}
+
+/// A user-supplied validator for a field, argument, or whole input object.
+///
+/// `E` defaults to `String` so existing implementations that return
+/// `Result<(), String>` keep compiling unchanged; implementations that need
+/// structured error codes can return `Result<(), ValidatorError>` (or any
+/// other type that converts into one) instead.
+#[async_trait::async_trait]
+pub trait CustomValidator<T: Send + Sync, E: Into<ValidatorError> = String>: Send + Sync {
+    async fn check(&self, ctx: &Context<'_>, value: &T) -> Result<(), E>;
+}
+
+/// Resolves the human-readable message for a built-in validator.
+///
+/// The derive macro looks this up via `ctx.data_opt::<Box<dyn
+/// ValidatorMessageFormatter>>()` before falling back to
+/// [`DefaultValidatorMessageFormatter`], so an application can register its
+/// own formatter (e.g. keyed off a request's `Accept-Language` header) to
+/// translate or reword validation failures without reimplementing every
+/// built-in validator.
+pub trait ValidatorMessageFormatter: Send + Sync {
+    fn maximum(&self, value: f64, maximum: f64) -> String {
+        DefaultValidatorMessageFormatter.maximum(value, maximum)
+    }
+
+    fn minimum(&self, value: f64, minimum: f64) -> String {
+        DefaultValidatorMessageFormatter.minimum(value, minimum)
+    }
+
+    fn min_items(&self, len: usize, min_items: usize) -> String {
+        DefaultValidatorMessageFormatter.min_items(len, min_items)
+    }
+
+    fn max_items(&self, len: usize, max_items: usize) -> String {
+        DefaultValidatorMessageFormatter.max_items(len, max_items)
+    }
+
+    fn unique_items(&self) -> String {
+        DefaultValidatorMessageFormatter.unique_items()
+    }
+
+    /// The message for a `not(maximum = "...")` combinator whose child
+    /// unexpectedly passed (the value was less than or equal to `maximum`).
+    fn not_maximum(&self, value: f64, maximum: f64) -> String {
+        DefaultValidatorMessageFormatter.not_maximum(value, maximum)
+    }
+
+    /// The message for a `not(minimum = "...")` combinator whose child
+    /// unexpectedly passed (the value was greater than or equal to `minimum`).
+    fn not_minimum(&self, value: f64, minimum: f64) -> String {
+        DefaultValidatorMessageFormatter.not_minimum(value, minimum)
+    }
+}
+
+/// The built-in English messages, used when no [`ValidatorMessageFormatter`]
+/// is registered in schema data.
+pub struct DefaultValidatorMessageFormatter;
+
+impl ValidatorMessageFormatter for DefaultValidatorMessageFormatter {
+    fn maximum(&self, value: f64, maximum: f64) -> String {
+        format!(
+            "the value is {}, must be less than or equal to {}",
+            value, maximum
+        )
+    }
+
+    fn minimum(&self, value: f64, minimum: f64) -> String {
+        format!(
+            "the value is {}, must be greater than or equal to {}",
+            value, minimum
+        )
+    }
+
+    fn min_items(&self, len: usize, min_items: usize) -> String {
+        format!(
+            "the list length is {}, must be greater than or equal to {}",
+            len, min_items
+        )
+    }
+
+    fn max_items(&self, len: usize, max_items: usize) -> String {
+        format!(
+            "the list length is {}, must be less than or equal to {}",
+            len, max_items
+        )
+    }
+
+    fn unique_items(&self) -> String {
+        "the list contains duplicate items, all items must be unique".to_string()
+    }
+
+    fn not_maximum(&self, value: f64, maximum: f64) -> String {
+        format!(
+            "the value is {}, must not be less than or equal to {}",
+            value, maximum
+        )
+    }
+
+    fn not_minimum(&self, value: f64, minimum: f64) -> String {
+        format!(
+            "the value is {}, must not be greater than or equal to {}",
+            value, minimum
+        )
+    }
+}
+
+/// Used by derive-generated code; not part of the public API.
+#[doc(hidden)]
+pub fn resolve_formatter(ctx: &Context<'_>) -> &dyn ValidatorMessageFormatter {
+    ctx.data_opt::<Box<dyn ValidatorMessageFormatter>>()
+        .map(|formatter| formatter.as_ref())
+        .unwrap_or(&DefaultValidatorMessageFormatter)
+}
@@ -260,6 +260,264 @@ pub async fn test_custom_validator() {
     );
 }
 
+#[tokio::test]
+pub async fn test_validator_on_input_object() {
+    struct RangeValidator;
+
+    #[async_trait::async_trait]
+    impl CustomValidator<MyInput> for RangeValidator {
+        async fn check(&self, _ctx: &Context<'_>, value: &MyInput) -> Result<(), String> {
+            if value.start < value.end {
+                Ok(())
+            } else {
+                Err(format!(
+                    "start ({}) must be less than end ({})",
+                    value.start, value.end
+                ))
+            }
+        }
+    }
+
+    #[derive(InputObject)]
+    #[graphql(validator(custom = "RangeValidator"))]
+    struct MyInput {
+        start: i32,
+        end: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, input: MyInput) -> i32 {
+            input.end - input.start
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value(input: {start: 1, end: 5}) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 4 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(input: {start: 5, end: 1}) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "MyInput": start (5) must be less than end (1) (occurred while parsing "MyInput")"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 16
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_custom_validator_with_error_extensions() {
+    struct MyValidator {
+        expect: i32,
+    }
+
+    impl MyValidator {
+        pub fn new(n: i32) -> Self {
+            MyValidator { expect: n }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CustomValidator<i32, ValidatorError> for MyValidator {
+        async fn check(&self, _ctx: &Context<'_>, value: &i32) -> Result<(), ValidatorError> {
+            if *value == self.expect {
+                Ok(())
+            } else {
+                Err(
+                    ValidatorError::new(format!("expect {}, actual {}", self.expect, value))
+                        .extend_with(|_, e| e.set("code", "OUT_OF_RANGE")),
+                )
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(validator(custom = "MyValidator::new(100)"))] n: i32,
+        ) -> i32 {
+            n
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let err = schema
+        .execute("{ value(n: 11) }")
+        .await
+        .into_result()
+        .unwrap_err()
+        .remove(0);
+    assert_eq!(
+        err.message,
+        r#"Failed to parse "Int": expect 100, actual 11"#.to_string()
+    );
+    assert_eq!(
+        err.extensions.unwrap().get("code"),
+        Some(&value!("OUT_OF_RANGE"))
+    );
+}
+
+#[tokio::test]
+pub async fn test_validator_or_combinator() {
+    struct IsSentinel;
+
+    impl IsSentinel {
+        pub fn new() -> Self {
+            IsSentinel
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CustomValidator<i32> for IsSentinel {
+        async fn check(&self, _ctx: &Context<'_>, value: &i32) -> Result<(), String> {
+            if *value == -1 {
+                Ok(())
+            } else {
+                Err("not the sentinel value".to_string())
+            }
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(validator(or(minimum = "0", custom = "IsSentinel::new()")))] n: i32,
+        ) -> i32 {
+            n
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value(n: 5) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 5 })
+    );
+    assert_eq!(
+        schema
+            .execute("{ value(n: -1) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": -1 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: -2) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the value is -2, must be greater than or equal to 0; not the sentinel value"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_validator_and_not_combinators() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(validator(and(minimum = "0", maximum = "10")))] n: i32,
+            #[graphql(validator(not(minimum = "5")))] m: i32,
+        ) -> i32 {
+            n + m
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value(n: 5, m: 1) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 6 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: 11, m: 1) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the value is 11, must be less than or equal to 10"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: 5, m: 5) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the value is 5, must not be greater than or equal to 5"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 18
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}
+
 #[tokio::test]
 pub async fn test_list_validator() {
     struct Query;
@@ -301,3 +559,219 @@ pub async fn test_list_validator() {
         }]
     );
 }
+
+#[tokio::test]
+pub async fn test_list_validator_min_max_items() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(validator(min_items = "2", max_items = "3"))] n: Vec<i32>,
+        ) -> i32 {
+            n.into_iter().sum()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1, 2, 3]) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 6 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the list length is 1, must be greater than or equal to 2"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1, 2, 3, 4]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the list length is 4, must be less than or equal to 3"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_list_validator_unique_items() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, #[graphql(validator(unique_items))] n: Vec<i32>) -> i32 {
+            n.into_iter().sum()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1, 2, 3]) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 6 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1, 2, 1]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the list contains duplicate items, all items must be unique"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_validator_message_localization() {
+    struct FrenchMessages;
+
+    impl ValidatorMessageFormatter for FrenchMessages {
+        fn maximum(&self, value: f64, maximum: f64) -> String {
+            format!(
+                "la valeur est {}, doit être inférieure ou égale à {}",
+                value, maximum
+            )
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, #[graphql(validator(maximum = "10"))] n: i32) -> i32 {
+            n
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Box::new(FrenchMessages) as Box<dyn ValidatorMessageFormatter>)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: 11) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": la valeur est 11, doit être inférieure ou égale à 10"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_list_validator_combined_with_min_items() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(
+            &self,
+            #[graphql(validator(maximum = "3", list, min_items = "2"))] n: Vec<i32>,
+        ) -> i32 {
+            n.into_iter().sum()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1, 2, 3]) }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 6 })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the list length is 1, must be greater than or equal to 2"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ value(n: [1, 2, 4]) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "Int": the value is 4, must be less than or equal to 3"#
+                .to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 12
+            }],
+            path: vec![PathSegment::Field("value".to_string())],
+            extensions: None
+        }]
+    );
+}